@@ -1,10 +1,43 @@
 use setver::SetVersion;
 use std::env::args;
+use std::str::FromStr;
+
+/// Selects how this binary prints its result, mirroring `sequoia-sq`'s `OutputFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+	/// The aligned human-readable table (the default).
+	Human,
+	/// A single JSON object, for machine-readable tooling pipelines. Requires the `json-output` feature.
+	Json,
+}
+
+impl FromStr for OutputFormat {
+	type Err = String;
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"human" => Ok(Self::Human),
+			"json" => Ok(Self::Json),
+			other => Err(format!("unknown output format '{}', expected 'human' or 'json'", other)),
+		}
+	}
+}
 
 fn main() {
 	let mut args = args();
 	args.next();
-	if let Some(mut version) = args.next() {
+
+	let mut output_format = OutputFormat::Human;
+	let mut version_arg = None;
+	while let Some(arg) = args.next() {
+		if arg == "--output-format" {
+			let value = args.next().expect("--output-format requires an argument");
+			output_format = value.parse().expect("invalid --output-format value");
+		} else {
+			version_arg = Some(arg);
+		}
+	}
+
+	if let Some(mut version) = version_arg {
 		if &version == "-" {
 			version = String::new();
 			std::io::stdin().read_line(&mut version).expect("couldn't read setver from stdin");
@@ -12,23 +45,68 @@ fn main() {
 		}
 
 		let canonicalized = version.parse::<SetVersion>().expect("invalid setver version");
-		let canonicalized_str = canonicalized.to_string();
-		let original_width = version.len().max("direct".len());
-		let canonical_width = canonicalized_str.len().max("canonicalized".len());
-		println!(
-			"                    {:>original_width$} {:>canonical_width$}
+
+		match output_format {
+			OutputFormat::Human => print_human(&version, &canonicalized),
+			OutputFormat::Json => print_json(&canonicalized),
+		}
+	} else {
+		eprintln!("usage: alternative_representations [--output-format {{human,json}}] SETVER_VERSION");
+	}
+}
+
+fn print_human(version: &str, canonicalized: &SetVersion) {
+	let canonicalized_str = canonicalized.to_string();
+	let original_width = version.len().max("direct".len());
+	let canonical_width = canonicalized_str.len().max("canonicalized".len());
+	println!(
+		"                    {:>original_width$} {:>canonical_width$}
 set representation  {:>original_width$} {:>canonical_width$}
 integralternative   {:>original_width$} {:>canonical_width$}",
-			"direct",
-			"canonicalized",
-			version,
-			canonicalized_str,
-			SetVersion::string_to_integralternative(&version),
-			canonicalized.to_integralternative(),
-			original_width = original_width,
-			canonical_width = canonical_width
-		);
-	} else {
-		eprintln!("usage: alternative_representations SETVER_VERSION");
+		"direct",
+		"canonicalized",
+		version,
+		canonicalized_str,
+		SetVersion::string_to_integralternative(version),
+		canonicalized.to_integralternative(),
+		original_width = original_width,
+		canonical_width = canonical_width
+	);
+}
+
+#[cfg(feature = "json-output")]
+fn print_json(canonicalized: &SetVersion) {
+	let output = serde_json::json!({
+		"canonical": canonicalized.to_string(),
+		"integralternative": integralternative_to_decimal(canonicalized),
+		"set": canonicalized,
+	});
+	println!("{}", output);
+}
+
+#[cfg(not(feature = "json-output"))]
+fn print_json(_canonicalized: &SetVersion) {
+	eprintln!("json output requires the 'json-output' feature; rebuild with `--features json-output`");
+	std::process::exit(1);
+}
+
+/// Renders the integralternative as a decimal string so it survives versions with more than 128 braces.
+#[cfg(feature = "json-output")]
+fn integralternative_to_decimal(version: &SetVersion) -> String {
+	let mut digits = version.to_integralternative_bytes();
+	if digits.iter().all(|&byte| byte == 0) {
+		return "0".to_owned();
+	}
+
+	let mut decimal = Vec::new();
+	while !digits.iter().all(|&byte| byte == 0) {
+		let mut remainder = 0u32;
+		for byte in digits.iter_mut() {
+			let value = (remainder << 8) | u32::from(*byte);
+			*byte = (value / 10) as u8;
+			remainder = value % 10;
+		}
+		decimal.push(std::char::from_digit(remainder, 10).unwrap());
 	}
+	decimal.iter().rev().collect()
 }
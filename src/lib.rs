@@ -1,17 +1,24 @@
 //! SetVer comprehension for Rust.
 
 #![deny(missing_docs, clippy::all)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use std::collections::BTreeSet;
-use std::fmt::Display;
-use std::rc::Rc;
-use std::str::FromStr;
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::str::FromStr;
 
 /// A SetVer version specification.
 /// # Implementation details
-/// This struct is implemented using HashSet from the standard library.
-/// Therefore, it is not usable in no-std environments right now.
-#[derive(Eq, PartialEq, Clone, Debug, Ord, PartialOrd, Default)]
+/// This struct is implemented using a `BTreeSet` of reference-counted children.
+/// It only depends on `alloc`, so it works in `no_std` environments when the default `std` feature is disabled.
+#[derive(Clone, Debug, Default)]
 pub struct SetVersion {
 	/// Making this an ordered set guarantees that all iterations are performed in order, which gives some nice guarantees for faster implementations.
 	versions: BTreeSet<Rc<SetVersion>>,
@@ -46,7 +53,7 @@ impl SetVersion {
 
 	/// Returns whether this SetVer version is a strict subset of the other version, according to standard set laws.
 	pub fn is_strict_subset(&self, other: &SetVersion) -> bool {
-		!other.is_superset(self)
+		self.is_subset(other) && self != other
 	}
 	/// Returns whether this SetVer version is a superset of the other version, according to standard set laws.
 	pub fn is_superset(&self, other: &SetVersion) -> bool {
@@ -55,7 +62,7 @@ impl SetVersion {
 
 	/// Returns whether this SetVer version is a strict superset of the other version, according to standard set laws.
 	pub fn is_strict_superset(&self, other: &SetVersion) -> bool {
-		!other.is_subset(self)
+		self.is_superset(other) && self != other
 	}
 
 	/// Adds the given version as a child version. This is useful when constructing a parent version for one or many previous child versions.
@@ -124,6 +131,32 @@ impl SetVersion {
 		bytes
 	}
 
+	/// Decodes a SetVersion from its integralternative bits, the mirror of `string_to_integralternative_bytes`.
+	/// Each bit maps to a brace character (`false` → `{`, `true` → `}`) in order, and the resulting string is parsed normally.
+	pub fn from_integralternative_bits(bits: &[bool]) -> Result<SetVersion, SetVerParseError> {
+		let string = bits.iter().map(|&bit| if bit { '}' } else { '{' }).collect::<String>();
+		string.parse()
+	}
+
+	/// Decodes a SetVersion from integralternative bytes as returned by `to_integralternative_bytes`/`string_to_integralternative_bytes`,
+	/// i.e. with the first byte holding the most significant bits. The zero bits that pad the first byte are skipped.
+	pub fn from_integralternative_bytes(bytes: &[u8]) -> Result<SetVersion, SetVerParseError> {
+		let bits = bytes
+			.iter()
+			.flat_map(|byte| (0..8).rev().map(move |bit_index| (byte >> bit_index) & 1 == 1))
+			.collect::<Vec<_>>();
+		// At most 7 leading zero bits are padding; only one padding length leaves a balanced set.
+		let padding = bits.iter().take(7).take_while(|&&bit| !bit).count();
+		let mut result = Self::from_integralternative_bits(&bits);
+		for skip in 1..=padding {
+			if result.is_ok() {
+				break;
+			}
+			result = Self::from_integralternative_bits(&bits[skip..]);
+		}
+		result
+	}
+
 	/// Does the "byte packing" required for the simple integralternative functions.
 	fn u128_from_vec(vec: Vec<u8>) -> u128 {
 		if vec.len() > 128 / 8 {
@@ -135,11 +168,104 @@ impl SetVersion {
 		}
 		result
 	}
+
+	/// Constructs the von Neumann ordinal representation of the natural number `n`, i.e. `n = {0, 1, ..., n-1}`.
+	/// `SetVersion::from_natural(0)` is the empty set.
+	///
+	/// Builds up the chain incrementally in a `Vec<Rc<SetVersion>>` of the previously constructed ordinals, reusing the same
+	/// `Rc`s as children everywhere instead of re-deriving each smaller ordinal from scratch. Each ordinal's children are
+	/// collected directly from the already-ascending `ordinals` prefix instead of inserted into the `BTreeSet` one at a time,
+	/// and comparisons between shared `Rc`s short-circuit via pointer identity (see `Ord for SetVersion` below) rather than
+	/// walking the whole shared structure — together this keeps construction polynomial instead of the combinatorial blowup
+	/// that naive structural comparison across the shared DAG would otherwise cause.
+	pub fn from_natural(n: usize) -> SetVersion {
+		let mut ordinals = Vec::with_capacity(n + 1);
+		ordinals.push(Rc::new(SetVersion::default()));
+		for i in 1..=n {
+			let versions = ordinals[..i].iter().cloned().collect();
+			ordinals.push(Rc::new(SetVersion { versions }));
+		}
+		(*ordinals[n]).clone()
+	}
+
+	/// Returns the natural number this SetVersion represents, if it is a von Neumann ordinal, i.e. a well-ordered chain `{0, 1, ..., n-1}`.
+	/// Returns `None` for any SetVersion that is not of this form.
+	pub fn to_natural(&self) -> Option<usize> {
+		let n = self.versions.len();
+		if *self == Self::from_natural(n) {
+			Some(n)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the successor ordinal `self ∪ {self}`, i.e. `self + 1` under von Neumann ordinal arithmetic.
+	pub fn successor(&self) -> SetVersion {
+		let mut result = self.clone();
+		result.add_child_version(Rc::new(self.clone()));
+		result
+	}
+
+	/// Applies `successor` to `version` `steps` times. Shared by `checked_add`/`checked_mul` so that, once their
+	/// operands are validated as naturals, stepping forward never re-validates or re-derives any already-built prefix.
+	fn step_successor(mut version: SetVersion, steps: usize) -> SetVersion {
+		for _ in 0..steps {
+			version = version.successor();
+		}
+		version
+	}
+
+	/// Adds two natural numbers under their von Neumann ordinal interpretation. Returns `None` if either side is not a natural number or the sum overflows `usize`.
+	pub fn checked_add(&self, other: &SetVersion) -> Option<SetVersion> {
+		let n = self.to_natural()?;
+		let m = other.to_natural()?;
+		n.checked_add(m)?;
+		Some(Self::step_successor(self.clone(), m))
+	}
+
+	/// Multiplies two natural numbers under their von Neumann ordinal interpretation. Returns `None` if either side is not a natural number or the product overflows `usize`.
+	pub fn checked_mul(&self, other: &SetVersion) -> Option<SetVersion> {
+		let n = self.to_natural()?;
+		let m = other.to_natural()?;
+		let product = n.checked_mul(m)?;
+		Some(Self::step_successor(SetVersion::default(), product))
+	}
+}
+
+/// Structural equality, short-circuited by pointer identity.
+///
+/// `from_natural`'s memoized ordinals share `Rc`s across many versions, so two equal `SetVersion`s are
+/// very often the exact same allocation; without the `ptr::eq` fast path, every comparison still walks
+/// the whole shared substructure from scratch (Rust's derived `Eq`/`Ord` has no such shortcut), which
+/// turns otherwise-polynomial construction and lookup code into something exponential.
+impl PartialEq for SetVersion {
+	fn eq(&self, other: &Self) -> bool {
+		core::ptr::eq(self, other) || self.versions == other.versions
+	}
+}
+
+impl Eq for SetVersion {}
+
+/// Structural ordering, short-circuited by pointer identity for the same reason as `PartialEq` above.
+impl PartialOrd for SetVersion {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for SetVersion {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		if core::ptr::eq(self, other) {
+			core::cmp::Ordering::Equal
+		} else {
+			self.versions.cmp(&other.versions)
+		}
+	}
 }
 
 impl Display for SetVersion {
 	/// The stringified version is always in canonical form, meaning that small sets are printed first.
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "{{")?;
 		for version in &self.versions {
 			version.fmt(f)?;
@@ -161,6 +287,26 @@ impl From<&SetVersion> for u128 {
 	}
 }
 
+impl TryFrom<u128> for SetVersion {
+	type Error = SetVerParseError;
+
+	/// Reconstructs a SetVersion from its integralternative.
+	///
+	/// Because a canonical SetVer string always opens with `{` (a `0` bit), encoding to `u128` silently drops any leading zero bits.
+	/// This searches, starting from the integer's own minimal bit width, for the smallest number of leading zero bits that reconstructs
+	/// a single, fully-balanced top-level set.
+	fn try_from(value: u128) -> Result<Self, Self::Error> {
+		let minimum_bits = (128 - value.leading_zeros() as usize).max(1);
+		for total_bits in minimum_bits..=128 {
+			let bits = (0..total_bits).rev().map(|bit_index| (value >> bit_index) & 1 == 1).collect::<Vec<_>>();
+			if let Ok(version) = SetVersion::from_integralternative_bits(&bits) {
+				return Ok(version);
+			}
+		}
+		Err(SetVerParseError::NoValidLength)
+	}
+}
+
 impl PartialEq<u128> for SetVersion {
 	/// Checks whether the integer is the canonical integralternative of this setver.
 	fn eq(&self, other: &u128) -> bool {
@@ -179,32 +325,65 @@ impl PartialEq<&str> for SetVersion {
 	}
 }
 
+/// Serializes and deserializes a SetVersion as a nested JSON-style array of arrays, e.g. `{}` ↔ `[]` and `{{}}` ↔ `[[]]`.
+/// Deserialization enforces the same uniqueness guarantee as `FromStr`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for SetVersion {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.collect_seq(self.versions.iter().map(Rc::as_ref))
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SetVersion {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let children = Vec::<SetVersion>::deserialize(deserializer)?;
+		let mut versions = BTreeSet::new();
+		for child in children {
+			if !versions.insert(Rc::new(child)) {
+				return Err(serde::de::Error::custom(SetVerParseError::NonUniqueElements));
+			}
+		}
+		Ok(Self { versions })
+	}
+}
+
 /// The errors that can happen when parsing a SetVer.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum SetVerParseError {
-	/// An illegal character is in the parsed string. Stores the illegal character.
-	IllegalCharacter(char),
+	/// An illegal character is in the parsed string. Stores the illegal character and its byte offset.
+	IllegalCharacter(char, usize),
 	/// A set contains non-unique elements (sets).
 	NonUniqueElements,
-	/// A curly brace is unclosed.
-	UnclosedBrace,
+	/// A curly brace is unclosed. Stores the byte offset of the innermost brace that was never closed.
+	UnclosedBrace(usize),
 	/// The string is empty.
 	Empty,
-	/// There's more than one set here.
-	TooManySets,
+	/// There's more than one set here. Stores the byte offset of the first character after the first closed set.
+	TooManySets(usize),
+	/// No amount of leading zero bits turns this integralternative into a valid, fully-balanced top-level set.
+	NoValidLength,
 }
 
 impl Display for SetVerParseError {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(
 			f,
 			"{}",
 			match &self {
-				Self::IllegalCharacter(c) => format!("Illegal character '{}'", c),
+				Self::IllegalCharacter(c, offset) => format!("Illegal character '{}' at byte offset {}", c, offset),
 				Self::NonUniqueElements => "Set contains non-unique subsets".to_string(),
-				Self::UnclosedBrace => "Unclosed set brace".to_string(),
+				Self::UnclosedBrace(offset) => format!("Unclosed set brace opened at byte offset {}", offset),
 				Self::Empty => "Empty string".to_string(),
-				Self::TooManySets => "Too many sets (more than one)".to_string(),
+				Self::TooManySets(offset) => format!("Too many sets (more than one), starting at byte offset {}", offset),
+				Self::NoValidLength =>
+					"No leading-zero-bit padding reconstructs a valid set from this integralternative".to_string(),
 			}
 		)
 	}
@@ -212,55 +391,161 @@ impl Display for SetVerParseError {
 
 impl FromStr for SetVersion {
 	type Err = SetVerParseError;
+	/// Parses in a single linear pass using an explicit stack of partially-built sets, one per currently open brace,
+	/// rather than slicing the input and re-parsing each substring recursively.
 	fn from_str(value: &str) -> Result<Self, Self::Err> {
 		// The smallest allowed setver specification is "{}" at length 2.
 		if value.len() < 2 {
 			return Err(SetVerParseError::Empty);
 		}
-		let mut chars = value.chars();
-		let open_curly = chars.next().unwrap();
+		let mut chars = value.char_indices();
+		let (_, open_curly) = chars.next().unwrap();
 		if open_curly != '{' {
-			return Err(SetVerParseError::IllegalCharacter(open_curly));
+			return Err(SetVerParseError::IllegalCharacter(open_curly, 0));
 		}
 
-		// Find the matching brace.
-		let mut brace_level = 1;
-		let mut inner_sets = vec!["".to_owned()];
-		for next_char in &mut chars {
-			match next_char {
-				'{' => brace_level += 1,
-				'}' => brace_level -= 1,
-				_ => return Err(SetVerParseError::IllegalCharacter(next_char)),
-			}
-			if brace_level == 0 {
-				break;
+		// The bottom of the stack is the top-level set; `open_offsets[i]` is the byte offset of the `{` that opened `stack[i]`.
+		let mut stack = alloc::vec![SetVersion::default()];
+		let mut open_offsets = alloc::vec![0];
+		let mut finished = None;
+
+		for (offset, character) in chars {
+			if finished.is_some() {
+				return Err(SetVerParseError::TooManySets(offset));
 			}
-			inner_sets.last_mut().unwrap().push(next_char);
-			if brace_level == 1 {
-				inner_sets.push("".to_owned());
+			match character {
+				'{' => {
+					stack.push(SetVersion::default());
+					open_offsets.push(offset);
+				}
+				'}' => {
+					// There is always at least one open brace here: `finished` is only set once the stack empties,
+					// and we just checked it is still `None`.
+					let child = stack.pop().unwrap();
+					open_offsets.pop();
+					match stack.last_mut() {
+						Some(parent) => {
+							let children_before = parent.versions.len();
+							parent.add_child_version(Rc::new(child));
+							if parent.versions.len() == children_before {
+								return Err(SetVerParseError::NonUniqueElements);
+							}
+						}
+						None => finished = Some(child),
+					}
+				}
+				_ => return Err(SetVerParseError::IllegalCharacter(character, offset)),
 			}
 		}
-		if brace_level != 0 {
-			return Err(SetVerParseError::UnclosedBrace);
-		}
-		if chars.next() != None {
-			return Err(SetVerParseError::TooManySets);
-		}
 
-		// The last set is a still-empty character collector if we got braces to match correctly.
-		inner_sets.remove(inner_sets.len() - 1);
-		if inner_sets.is_empty() {
-			return Ok(Self::default());
+		finished.ok_or_else(|| SetVerParseError::UnclosedBrace(*open_offsets.last().unwrap()))
+	}
+}
+
+/// A requirement that a [`SetVersion`] must satisfy, analogous to `semver`'s `VersionReq`.
+///
+/// A requirement is a comma-separated list of [`Comparator`]s, all of which must match.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct SetVerReq {
+	comparators: Vec<Comparator>,
+}
+
+impl SetVerReq {
+	/// Returns whether the given version satisfies every comparator in this requirement.
+	/// ```rust
+	/// use setver::SetVerReq;
+	/// let req: SetVerReq = "⊇{}".parse().unwrap();
+	/// assert!(req.matches(&"{{}}".parse().unwrap()));
+	/// assert!(!"={}".parse::<SetVerReq>().unwrap().matches(&"{{}}".parse().unwrap()));
+	/// ```
+	pub fn matches(&self, version: &SetVersion) -> bool {
+		self.comparators.iter().all(|comparator| comparator.matches(version))
+	}
+}
+
+impl FromStr for SetVerReq {
+	type Err = SetVerReqParseError;
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		let comparators = value.split(',').map(str::parse).collect::<Result<Vec<Comparator>, _>>()?;
+		Ok(Self { comparators })
+	}
+}
+
+/// A single comparator within a [`SetVerReq`], e.g. `⊇{}` or the ASCII alias `>={}`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Comparator {
+	op: Op,
+	version: SetVersion,
+}
+
+impl Comparator {
+	fn matches(&self, version: &SetVersion) -> bool {
+		match self.op {
+			Op::Superset => version.is_superset(&self.version),
+			Op::StrictSuperset => version.is_strict_superset(&self.version),
+			Op::Subset => version.is_subset(&self.version),
+			Op::StrictSubset => version.is_strict_subset(&self.version),
+			Op::Exact => version == &self.version,
 		}
+	}
+}
+
+/// The recognized operators, checked in order so that multi-character ASCII aliases are matched before their prefixes.
+const OPERATORS: &[(&str, Op)] = &[
+	(">=", Op::Superset),
+	("⊇", Op::Superset),
+	(">", Op::StrictSuperset),
+	("⊃", Op::StrictSuperset),
+	("<=", Op::Subset),
+	("⊆", Op::Subset),
+	("<", Op::StrictSubset),
+	("⊂", Op::StrictSubset),
+	("=", Op::Exact),
+];
 
-		let versions = inner_sets
+impl FromStr for Comparator {
+	type Err = SetVerReqParseError;
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		let (op, rest) = OPERATORS
 			.iter()
-			.map(|string_set| string_set.parse::<SetVersion>().map(Rc::new))
-			.collect::<Result<BTreeSet<Rc<SetVersion>>, SetVerParseError>>()?;
-		if versions.len() < inner_sets.len() {
-			return Err(SetVerParseError::NonUniqueElements);
+			.find(|(token, _)| value.starts_with(token))
+			.map(|(token, op)| (*op, &value[token.len()..]))
+			.ok_or(SetVerReqParseError::UnknownOperator)?;
+		let version = rest.parse::<SetVersion>().map_err(SetVerReqParseError::InvalidVersion)?;
+		Ok(Self { op, version })
+	}
+}
+
+/// The relational operator of a [`Comparator`], each delegating to the corresponding set-lattice predicate on [`SetVersion`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Op {
+	/// `⊇` or `>=`: the matched version is a superset of (or equal to) the comparator's version.
+	Superset,
+	/// `⊃` or `>`: the matched version is a strict superset of the comparator's version.
+	StrictSuperset,
+	/// `⊆` or `<=`: the matched version is a subset of (or equal to) the comparator's version.
+	Subset,
+	/// `⊂` or `<`: the matched version is a strict subset of the comparator's version.
+	StrictSubset,
+	/// `=`: the matched version is exactly equal to the comparator's version.
+	Exact,
+}
+
+/// The errors that can happen when parsing a [`SetVerReq`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SetVerReqParseError {
+	/// The comparator does not start with a recognized operator (`⊇`, `⊃`, `⊆`, `⊂`, `=`, or the ASCII aliases `>=`, `>`, `<=`, `<`).
+	UnknownOperator,
+	/// The version following the operator could not be parsed.
+	InvalidVersion(SetVerParseError),
+}
+
+impl Display for SetVerReqParseError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::UnknownOperator => write!(f, "Comparator does not start with a recognized operator"),
+			Self::InvalidVersion(error) => write!(f, "Invalid version in comparator: {}", error),
 		}
-		Ok(Self { versions })
 	}
 }
 
@@ -283,14 +568,16 @@ mod tests {
 	#[test]
 	fn parse_incorrect_setver() {
 		assert_eq!("".parse::<SetVersion>().unwrap_err(), SetVerParseError::Empty);
-		assert_eq!("asd".parse::<SetVersion>().unwrap_err(), SetVerParseError::IllegalCharacter('a'));
-		assert_eq!("{{b}}".parse::<SetVersion>().unwrap_err(), SetVerParseError::IllegalCharacter('b'));
+		assert_eq!("asd".parse::<SetVersion>().unwrap_err(), SetVerParseError::IllegalCharacter('a', 0));
+		assert_eq!("{{b}}".parse::<SetVersion>().unwrap_err(), SetVerParseError::IllegalCharacter('b', 2));
 		"{{}{}".parse::<SetVersion>().unwrap_err();
 		"}{}".parse::<SetVersion>().unwrap_err();
-		assert_eq!("{}{}".parse::<SetVersion>().unwrap_err(), SetVerParseError::TooManySets);
+		assert_eq!("{}{}".parse::<SetVersion>().unwrap_err(), SetVerParseError::TooManySets(2));
 		assert_eq!("{{}{}}".parse::<SetVersion>().unwrap_err(), SetVerParseError::NonUniqueElements);
 		assert_eq!("{{{}{}}{}}".parse::<SetVersion>().unwrap_err(), SetVerParseError::NonUniqueElements);
 		assert_eq!("{{}{{}{{}}}{{}{{}}}}".parse::<SetVersion>().unwrap_err(), SetVerParseError::NonUniqueElements);
+		assert_eq!("{{}".parse::<SetVersion>().unwrap_err(), SetVerParseError::UnclosedBrace(0));
+		assert_eq!("{{{}".parse::<SetVersion>().unwrap_err(), SetVerParseError::UnclosedBrace(1));
 	}
 
 	#[test]
@@ -312,4 +599,52 @@ mod tests {
 		assert_eq!(SetVersion::string_to_integralternative("{{{{}}{}}{{}}}"), 871);
 		assert_eq!(SetVersion::string_to_integralternative("{{{}}{{{}}{}}}"), 1591);
 	}
+
+	#[test]
+	fn integralternative_round_trip() {
+		for test_string in ["{{{{}}{}}{{}}}", "{{{}}{{{}}{}}}", "{{}{{{}}{{}{{}}}}}"] {
+			let version = test_string.parse::<SetVersion>().unwrap();
+			assert_eq!(SetVersion::try_from(version.to_integralternative()).unwrap(), version);
+		}
+	}
+
+	#[test]
+	fn natural_numbers() {
+		assert_eq!(SetVersion::from_natural(0), "{}");
+		assert_eq!(SetVersion::from_natural(1), "{{}}");
+		assert_eq!(SetVersion::from_natural(2), "{{}{{}}}");
+		assert_eq!(SetVersion::from_natural(3), "{{}{{}}{{}{{}}}}");
+
+		for n in 0..10 {
+			assert_eq!(SetVersion::from_natural(n).to_natural(), Some(n));
+		}
+		assert_eq!("{{{}}}".parse::<SetVersion>().unwrap().to_natural(), None);
+
+		assert_eq!(SetVersion::from_natural(2).successor(), SetVersion::from_natural(3));
+
+		assert_eq!(SetVersion::from_natural(2).checked_add(&SetVersion::from_natural(3)), Some(SetVersion::from_natural(5)));
+		assert_eq!(SetVersion::from_natural(2).checked_mul(&SetVersion::from_natural(3)), Some(SetVersion::from_natural(6)));
+		assert_eq!("{{{}}}".parse::<SetVersion>().unwrap().checked_add(&SetVersion::from_natural(1)), None);
+	}
+
+	#[test]
+	fn setver_req() {
+		let empty: SetVersion = "{}".parse().unwrap();
+		let one: SetVersion = "{{}}".parse().unwrap();
+		let two: SetVersion = "{{}{{}}}".parse().unwrap();
+
+		assert!("⊇{}".parse::<SetVerReq>().unwrap().matches(&one));
+		assert!(">={}".parse::<SetVerReq>().unwrap().matches(&empty));
+		assert!("⊃{}".parse::<SetVerReq>().unwrap().matches(&one));
+		assert!(!"⊃{}".parse::<SetVerReq>().unwrap().matches(&empty));
+		assert!("⊆{{}}".parse::<SetVerReq>().unwrap().matches(&empty));
+		assert!("<{{}}".parse::<SetVerReq>().unwrap().matches(&empty));
+		assert!(!"<{{}}".parse::<SetVerReq>().unwrap().matches(&one));
+		assert!("={{}}".parse::<SetVerReq>().unwrap().matches(&one));
+		assert!("⊇{},⊆{{}{{}}}".parse::<SetVerReq>().unwrap().matches(&one));
+		assert!(!"⊇{},⊆{}".parse::<SetVerReq>().unwrap().matches(&two));
+
+		assert_eq!("nope".parse::<Comparator>().unwrap_err(), SetVerReqParseError::UnknownOperator);
+		assert!(matches!(">=nope".parse::<SetVerReq>().unwrap_err(), SetVerReqParseError::InvalidVersion(_)));
+	}
 }